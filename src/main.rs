@@ -1,7 +1,10 @@
+use std::collections::HashMap;
 use std::fs::{self};
 use std::os::unix::fs::MetadataExt;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use rayon::prelude::*;
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 use walkdir::WalkDir;
 use users::{get_user_by_uid, get_group_by_gid};
@@ -9,12 +12,13 @@ use clap::{Arg, Command};
 
 /// Trait for displaying information with color.
 trait DisplayWithColor {
-    /// Displays the information with color and indentation based on depth.
+    /// Displays the information with color, prepending the given prefix to the
+    /// name (plain indentation or tree-connector glyphs depending on mode).
     ///
     /// # Arguments
     ///
-    /// * `depth` - The depth of the file or directory in the hierarchy.
-    fn display_with_color(&self, depth: usize);
+    /// * `prefix` - The string rendered immediately before the name.
+    fn display_with_color(&self, prefix: &str);
 }
 
 /// Struct to hold file information.
@@ -24,80 +28,367 @@ struct FileInfo {
     owner: String,
     group: String,
     permissions: String,
-    color: Color,
+    size: u64,
+    mtime: i64,
+    color: ColorSpec,
+}
+
+/// Key used to order a directory's entries.
+#[derive(Clone, Copy)]
+enum SortKey {
+    Name,
+    Size,
+    Time,
+}
+
+/// Renders a byte count the way `ls -h` does: plain below 1024, otherwise
+/// divided down by powers of 1024 to one decimal place with a unit suffix.
+///
+/// # Arguments
+///
+/// * `bytes` - The size in bytes.
+///
+/// # Returns
+///
+/// * `String` - The human-readable representation (e.g. `1.2K`, `3.4M`).
+fn human_readable_size(bytes: u64) -> String {
+    const UNITS: [char; 4] = ['K', 'M', 'G', 'T'];
+    if bytes < 1024 {
+        return bytes.to_string();
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", size, UNITS[unit])
+}
+
+/// Compares two entries by the chosen sort key, with name as the tie-break.
+/// Directories have no meaningful size, so under `Size` they count as zero
+/// bytes and order among themselves by name.
+fn compare_entries(a: &FileInfo, b: &FileInfo, sort: SortKey, reverse: bool) -> std::cmp::Ordering {
+    let ordering = match sort {
+        SortKey::Name => a.name.cmp(&b.name),
+        SortKey::Size => {
+            let sa = if a.file_type == "Directory" { 0 } else { a.size };
+            let sb = if b.file_type == "Directory" { 0 } else { b.size };
+            sa.cmp(&sb).then_with(|| a.name.cmp(&b.name))
+        }
+        SortKey::Time => a.mtime.cmp(&b.mtime).then_with(|| a.name.cmp(&b.name)),
+    };
+    if reverse { ordering.reverse() } else { ordering }
+}
+
+/// Produces the display order for a depth-first listing, sorting the children
+/// *within each parent group* so that every subtree stays contiguous.
+///
+/// `infos` is the flat pre-order walk; `start..end` is a run of sibling
+/// subtrees rooted at the same depth. The resulting index order is appended
+/// to `out`, recursing into each subtree so grouping is never broken.
+fn order_group(infos: &[(usize, FileInfo)], start: usize, end: usize, sort: SortKey, reverse: bool, out: &mut Vec<usize>) {
+    if start >= end {
+        return;
+    }
+    let base = infos[start].0;
+
+    // Split the range into sibling subtrees: each starts at a `base`-depth
+    // entry and runs until the next one.
+    let mut siblings: Vec<(usize, usize)> = Vec::new();
+    let mut i = start;
+    while i < end {
+        let root = i;
+        i += 1;
+        while i < end && infos[i].0 > base {
+            i += 1;
+        }
+        siblings.push((root, i));
+    }
+
+    siblings.sort_by(|a, b| compare_entries(&infos[a.0].1, &infos[b.0].1, sort, reverse));
+
+    for (root, sub_end) in siblings {
+        out.push(root);
+        order_group(infos, root + 1, sub_end, sort, reverse, out);
+    }
 }
 
 impl DisplayWithColor for FileInfo {
-    fn display_with_color(&self, depth: usize) {
+    fn display_with_color(&self, prefix: &str) {
         let mut stdout = StandardStream::stdout(ColorChoice::Always);
-        let mut color_spec = ColorSpec::new();
-        color_spec.set_fg(Some(self.color));
-        stdout.set_color(&color_spec).unwrap();
-
-        // Print with indentation and symbols based on depth
-        let indent = "  ".repeat(depth);
-        let name_display = if depth > 0 {
-            format!("{}> {}", indent, self.name)
-        } else {
-            self.name.clone()
-        };
+        stdout.set_color(&self.color).unwrap();
+
+        let name_display = format!("{}{}", prefix, self.name);
 
         println!(
-            "{:<60} {:<10} {:<20} {:<20} {:<10}",
+            "{:<60} {:<10} {:<20} {:<20} {:<10} {:<10}",
             name_display,
             self.file_type,
             self.owner,
             self.group,
-            self.permissions
+            self.permissions,
+            human_readable_size(self.size)
         );
 
         stdout.reset().unwrap();
     }
 }
 
+/// Translates a semicolon-separated list of ANSI SGR codes (e.g. `01;32`)
+/// into a `termcolor::ColorSpec`.
+///
+/// # Arguments
+///
+/// * `codes` - The SGR code list as stored in an `LS_COLORS` value.
+///
+/// # Returns
+///
+/// * `ColorSpec` - The equivalent color specification.
+fn sgr_to_color_spec(codes: &str) -> ColorSpec {
+    let mut spec = ColorSpec::new();
+    for code in codes.split(';') {
+        match code.parse::<u8>() {
+            Ok(1) => {
+                spec.set_bold(true);
+            }
+            Ok(4) => {
+                spec.set_underline(true);
+            }
+            Ok(n @ 30..=37) => {
+                spec.set_fg(Some(ansi_color(n - 30)));
+            }
+            Ok(n @ 90..=97) => {
+                spec.set_fg(Some(ansi_color(n - 90)));
+                spec.set_intense(true);
+            }
+            _ => {}
+        }
+    }
+    spec
+}
+
+/// Maps a 0..=7 ANSI color offset to its `termcolor::Color`.
+fn ansi_color(offset: u8) -> Color {
+    match offset {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+/// Parses the `LS_COLORS` environment variable into a lookup table.
+///
+/// Each `:`-separated entry is a `key=value` pair where the value is a
+/// semicolon-separated list of ANSI SGR codes. Keys are either file-type
+/// tokens (`di`, `ln`, `ex`, `fi`, `or`) or glob patterns like `*.rs`.
+///
+/// # Returns
+///
+/// * `HashMap<String, ColorSpec>` - Mapping from key to color specification.
+fn parse_ls_colors() -> HashMap<String, ColorSpec> {
+    let mut map = HashMap::new();
+    if let Ok(value) = std::env::var("LS_COLORS") {
+        for entry in value.split(':') {
+            if let Some((key, codes)) = entry.split_once('=') {
+                if key.is_empty() || codes.is_empty() {
+                    continue;
+                }
+                map.insert(key.to_string(), sgr_to_color_spec(codes));
+            }
+        }
+    }
+    map
+}
+
+/// Builds the flat indentation prefix used outside tree mode: two spaces per
+/// level plus a `> ` marker, and nothing at all for the top-level entry.
+fn indent_prefix(depth: usize) -> String {
+    if depth > 0 {
+        format!("{}> ", "  ".repeat(depth))
+    } else {
+        String::new()
+    }
+}
+
+/// Computes the box-drawing prefix for each entry of a depth-first listing.
+///
+/// An entry is the last child of its parent when no later entry shares its
+/// depth before the walk pops back above it; ancestors that were themselves
+/// last children contribute blank continuation columns, the rest a `│`.
+///
+/// # Arguments
+///
+/// * `depths` - The per-entry depths in depth-first (pre-order) walk order.
+///
+/// # Returns
+///
+/// * `Vec<String>` - The connector prefix for each entry.
+fn tree_prefixes(depths: &[usize]) -> Vec<String> {
+    let mut prefixes = Vec::with_capacity(depths.len());
+    let mut ancestor_last: Vec<bool> = Vec::new();
+
+    for (i, &depth) in depths.iter().enumerate() {
+        // Is this entry the last child among its siblings?
+        let mut is_last = true;
+        for &next in &depths[i + 1..] {
+            if next == depth {
+                is_last = false;
+                break;
+            } else if next < depth {
+                break;
+            }
+        }
+
+        if ancestor_last.len() <= depth {
+            ancestor_last.resize(depth + 1, false);
+        }
+        ancestor_last[depth] = is_last;
+
+        let mut prefix = String::new();
+        if depth > 0 {
+            for &last in &ancestor_last[1..depth] {
+                prefix.push_str(if last { "    " } else { "│   " });
+            }
+            prefix.push_str(if is_last { "└── " } else { "├── " });
+        }
+        prefixes.push(prefix);
+    }
+
+    prefixes
+}
+
+/// Renders a directory's (or archive's) entries. In both modes siblings are
+/// sorted by the chosen key within their parent; tree mode then draws
+/// connector glyphs, otherwise entries are shown with flat indentation.
+fn render(infos: Vec<(usize, FileInfo)>, tree: bool, sort: SortKey, reverse: bool) {
+    // Reorder children within each parent group while keeping subtrees
+    // contiguous, so the depth-first grouping (and any tree glyphs) stay valid.
+    let mut order = Vec::with_capacity(infos.len());
+    order_group(&infos, 0, infos.len(), sort, reverse, &mut order);
+
+    if tree {
+        let depths: Vec<usize> = order.iter().map(|&i| infos[i].0).collect();
+        let prefixes = tree_prefixes(&depths);
+        for (&i, prefix) in order.iter().zip(prefixes.iter()) {
+            infos[i].1.display_with_color(prefix);
+        }
+    } else {
+        for &i in &order {
+            let (depth, file_info) = &infos[i];
+            file_info.display_with_color(&indent_prefix(*depth));
+        }
+    }
+}
+
+/// Options controlling a listing, gathered from the command line.
+struct ListOptions {
+    recursive: bool,
+    show_hidden: bool,
+    level: Option<usize>,
+    use_ignore: bool,
+    sort: SortKey,
+    reverse: bool,
+    tree: bool,
+}
+
 /// Lists files and directories with color-coded output.
 ///
 /// # Arguments
 ///
 /// * `paths` - A slice of PathBuf representing the paths to list.
-/// * `recursive` - A boolean flag indicating whether to list directories recursively.
-/// * `show_hidden` - A boolean flag indicating whether to show hidden files.
+/// * `opts` - The options controlling recursion, sorting, and rendering.
 ///
 /// # Returns
 ///
 /// * `io::Result<()>` - Result indicating success or failure.
-fn list_files_and_dirs(paths: &[PathBuf], recursive: bool, show_hidden: bool) -> io::Result<()> {
+fn list_files_and_dirs(paths: &[PathBuf], opts: &ListOptions) -> io::Result<()> {
+    let ListOptions { recursive, show_hidden, level, use_ignore, sort, reverse, tree } = *opts;
     let mut has_entries = false; // Track if any files or directories are found
+    let ls_colors = parse_ls_colors();
+    let names = NameCache::default();
+
+    // An explicit `--level` caps the walk regardless of `--recursive`;
+    // without it, `--recursive` means unlimited and otherwise one level deep.
+    let max_depth = match level {
+        Some(n) => n,
+        None if recursive => usize::MAX,
+        None => 1,
+    };
 
     // Print header
-    println!("{:<60} {:<10} {:<20} {:<20} {:<10}", "Name", "Type", "Owner", "Group", "Permissions");
+    println!("{:<60} {:<10} {:<20} {:<20} {:<10} {:<10}", "Name", "Type", "Owner", "Group", "Permissions", "Size");
 
     for path in paths {
         let path_str = path.to_str().unwrap_or("Unknown");
         println!("\nListing in: {}", path_str);
 
-        // Process each directory
-        for entry in WalkDir::new(path)
-            .max_depth(if recursive { usize::MAX } else { 1 }) // Adjust depth based on recursive flag
-            .into_iter()
-            .filter_map(Result::ok)
-        {
-            let path = entry.path();
-            let depth = entry.depth(); // Current depth in recursion
-
-            if !show_hidden && path.file_name().unwrap_or_default().to_str().map_or(false, |s| s.starts_with('.')) {
+        // Peek inside recognized archives instead of listing them as a file.
+        if path.is_file() {
+            if let Some(kind) = archive_kind(path) {
+                match list_archive(path, kind, &ls_colors, &names) {
+                    Ok(infos) => {
+                        if !infos.is_empty() {
+                            has_entries = true;
+                            render(infos, tree, sort, reverse);
+                        }
+                    }
+                    Err(e) => eprintln!("Error reading archive {}: {}", path_str, e),
+                }
                 continue;
             }
-            if path.is_file() {
-                has_entries = true; // Found at least one file
-                let file_info = create_file_info(path, Color::Green);
-                file_info.display_with_color(depth);
-            } else if path.is_dir() {
-                has_entries = true; // Found at least one directory
-                let file_info = create_file_info(path, Color::Blue);
-                file_info.display_with_color(depth);
+        }
+
+        // Gather the matching entries first so the per-entry stat-ing can run
+        // concurrently instead of serially on the main thread. When ignore
+        // rules are requested we swap in `ignore::WalkBuilder`, which exposes
+        // the same depth/path API as `WalkDir`.
+        let keep = |p: &Path| -> bool {
+            if !show_hidden
+                && p.file_name().unwrap_or_default().to_str().is_some_and(|s| s.starts_with('.'))
+            {
+                return false;
             }
+            p.is_file() || p.is_dir()
+        };
+
+        let entries: Vec<(usize, PathBuf)> = if use_ignore {
+            let mut builder = ignore::WalkBuilder::new(path);
+            builder.hidden(!show_hidden);
+            builder.max_depth(if max_depth == usize::MAX { None } else { Some(max_depth) });
+            builder
+                .build()
+                .filter_map(Result::ok)
+                .filter(|entry| keep(entry.path()))
+                .map(|entry| (entry.depth(), entry.path().to_path_buf()))
+                .collect()
+        } else {
+            WalkDir::new(path)
+                .max_depth(max_depth) // Depth capped by --level or --recursive
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|entry| keep(entry.path()))
+                .map(|entry| (entry.depth(), entry.path().to_path_buf()))
+                .collect()
+        };
+
+        if entries.is_empty() {
+            continue;
         }
+        has_entries = true;
+
+        // Fan the metadata work out across the rayon pool; `render` restores
+        // a stable order since parallel completion is unordered.
+        let infos: Vec<(usize, FileInfo)> = entries
+            .par_iter()
+            .map(|(depth, path)| (*depth, create_file_info(path, &ls_colors, &names)))
+            .collect();
+        render(infos, tree, sort, reverse);
     }
 
     if !has_entries {
@@ -107,22 +398,152 @@ fn list_files_and_dirs(paths: &[PathBuf], recursive: bool, show_hidden: bool) ->
     Ok(())
 }
 
+/// Resolves the `ColorSpec` for a path from the parsed `LS_COLORS` table.
+///
+/// Type tokens take precedence, then the longest-matching extension key is
+/// used, and finally the built-in default (blue directories, green files).
+///
+/// # Arguments
+///
+/// * `path` - The path whose style is being resolved.
+/// * `metadata` - The path's metadata.
+/// * `ls_colors` - The parsed `LS_COLORS` lookup table.
+///
+/// # Returns
+///
+/// * `ColorSpec` - The color specification to display the path with.
+fn resolve_color(path: &Path, metadata: &fs::Metadata, ls_colors: &HashMap<String, ColorSpec>) -> ColorSpec {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    // lstat so symlinks are recognized as links rather than their target's
+    // type; the target is stat-ed separately only to decide orphan-ness.
+    match fs::symlink_metadata(path) {
+        Ok(lstat) => {
+            let is_symlink = lstat.file_type().is_symlink();
+            let orphan = is_symlink && fs::metadata(path).is_err();
+            resolve_color_spec(
+                &name,
+                lstat.is_dir(),
+                is_symlink,
+                orphan,
+                lstat.mode() & 0o111 != 0,
+                ls_colors,
+            )
+        }
+        Err(_) => resolve_color_spec(
+            &name,
+            metadata.is_dir(),
+            false,
+            false,
+            metadata.mode() & 0o111 != 0,
+            ls_colors,
+        ),
+    }
+}
+
+/// Resolves a `ColorSpec` from the parsed `LS_COLORS` table given a name and
+/// a handful of type facts, independent of any on-disk metadata.
+///
+/// Type tokens take precedence, then the longest-matching extension key is
+/// used, and finally the built-in default (blue directories, green files).
+fn resolve_color_spec(
+    name: &str,
+    is_dir: bool,
+    is_symlink: bool,
+    orphan: bool,
+    executable: bool,
+    ls_colors: &HashMap<String, ColorSpec>,
+) -> ColorSpec {
+    let type_token = if orphan {
+        Some("or")
+    } else if is_symlink {
+        Some("ln")
+    } else if is_dir {
+        Some("di")
+    } else if executable {
+        Some("ex")
+    } else {
+        None
+    };
+
+    if let Some(token) = type_token {
+        if let Some(spec) = ls_colors.get(token) {
+            return spec.clone();
+        }
+    }
+
+    if !is_dir && !is_symlink {
+        // Longest-matching extension key wins (e.g. `*.tar.gz` over `*.gz`).
+        let best = ls_colors
+            .keys()
+            .filter(|k| k.starts_with("*.") && name.ends_with(&k[1..]))
+            .max_by_key(|k| k.len());
+        if let Some(key) = best {
+            return ls_colors[key].clone();
+        }
+        if let Some(spec) = ls_colors.get("fi") {
+            return spec.clone();
+        }
+    }
+
+    let mut spec = ColorSpec::new();
+    spec.set_fg(Some(if is_dir { Color::Blue } else { Color::Green }));
+    spec
+}
+
+/// Shared uid/gid resolution caches so parallel workers avoid redundant
+/// passwd/group lookups.
+#[derive(Default)]
+struct NameCache {
+    users: Mutex<HashMap<u32, String>>,
+    groups: Mutex<HashMap<u32, String>>,
+}
+
+impl NameCache {
+    /// Resolves a uid to a user name, memoizing the result.
+    fn owner(&self, uid: u32) -> String {
+        self.users
+            .lock()
+            .unwrap()
+            .entry(uid)
+            .or_insert_with(|| {
+                get_user_by_uid(uid)
+                    .map_or("Unknown".to_string(), |u| u.name().to_string_lossy().into_owned())
+            })
+            .clone()
+    }
+
+    /// Resolves a gid to a group name, memoizing the result.
+    fn group(&self, gid: u32) -> String {
+        self.groups
+            .lock()
+            .unwrap()
+            .entry(gid)
+            .or_insert_with(|| {
+                get_group_by_gid(gid)
+                    .map_or("Unknown".to_string(), |g| g.name().to_string_lossy().into_owned())
+            })
+            .clone()
+    }
+}
+
 /// Creates a FileInfo struct for a given path.
 ///
 /// # Arguments
 ///
 /// * `path` - A reference to a Path representing the file or directory.
-/// * `color` - The color to use for displaying the file or directory.
+/// * `ls_colors` - The parsed `LS_COLORS` lookup table used to pick a color.
+/// * `names` - Shared uid/gid name cache.
 ///
 /// # Returns
 ///
 /// * `FileInfo` - Struct containing file information.
-fn create_file_info(path: &Path, color: Color) -> FileInfo {
+fn create_file_info(path: &Path, ls_colors: &HashMap<String, ColorSpec>, names: &NameCache) -> FileInfo {
     let metadata = fs::metadata(path).unwrap();
     let file_type = if metadata.is_dir() { "Directory" } else { "File" }.to_string();
-    let owner = get_user_by_uid(metadata.uid()).map_or("Unknown".to_string(), |u| u.name().to_string_lossy().into_owned());
-    let group = get_group_by_gid(metadata.gid()).map_or("Unknown".to_string(), |g| g.name().to_string_lossy().into_owned());
+    let owner = names.owner(metadata.uid());
+    let group = names.group(metadata.gid());
     let permissions = format!("{:o}", metadata.mode() & 0o777);
+    let color = resolve_color(path, &metadata, ls_colors);
 
     FileInfo {
         name: path.file_name().unwrap_or_default().to_string_lossy().into_owned(),
@@ -130,10 +551,175 @@ fn create_file_info(path: &Path, color: Color) -> FileInfo {
         owner,
         group,
         permissions,
+        size: metadata.len(),
+        mtime: metadata.mtime(),
         color,
     }
 }
 
+/// Recognized archive container formats.
+enum ArchiveKind {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+/// Detects whether a path is an archive `bls` can peek into, preferring the
+/// extension and falling back to the leading magic bytes so extensionless or
+/// misnamed archives are still recognized.
+///
+/// # Arguments
+///
+/// * `path` - The candidate archive path.
+///
+/// # Returns
+///
+/// * `Option<ArchiveKind>` - The detected format, or `None` for ordinary files.
+fn archive_kind(path: &Path) -> Option<ArchiveKind> {
+    if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+        let name = name.to_ascii_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            return Some(ArchiveKind::TarGz);
+        } else if name.ends_with(".tar") {
+            return Some(ArchiveKind::Tar);
+        } else if name.ends_with(".zip") {
+            return Some(ArchiveKind::Zip);
+        }
+    }
+    archive_kind_from_magic(path)
+}
+
+/// Sniffs an archive format from a file's leading magic bytes.
+///
+/// Recognizes the gzip (`1f 8b`), zip (`PK\x03\x04`), and ustar (`ustar` at
+/// offset 257) signatures.
+fn archive_kind_from_magic(path: &Path) -> Option<ArchiveKind> {
+    use std::io::Read;
+    let mut file = fs::File::open(path).ok()?;
+    let mut buf = [0u8; 262];
+    let read = file.read(&mut buf).ok()?;
+    let buf = &buf[..read];
+
+    if buf.starts_with(&[0x1f, 0x8b]) {
+        Some(ArchiveKind::TarGz)
+    } else if buf.starts_with(b"PK\x03\x04") {
+        Some(ArchiveKind::Zip)
+    } else if buf.len() >= 262 && &buf[257..262] == b"ustar" {
+        Some(ArchiveKind::Tar)
+    } else {
+        None
+    }
+}
+
+/// Derives the indentation depth for an archive member from its stored path,
+/// ignoring a leading `./` that tar commonly prepends.
+fn archive_depth(name: &str) -> usize {
+    name.strip_prefix("./").unwrap_or(name).trim_end_matches('/').matches('/').count()
+}
+
+/// The stored metadata of a single archive member, as read from the container.
+struct ArchiveMember {
+    name: String,
+    is_dir: bool,
+    mode: u32,
+    owner: String,
+    group: String,
+    size: u64,
+    mtime: i64,
+}
+
+/// Builds a `FileInfo` for a single archive member from its stored metadata.
+fn archive_entry_info(member: &ArchiveMember, ls_colors: &HashMap<String, ColorSpec>) -> FileInfo {
+    let display_name = member.name.trim_end_matches('/').rsplit('/').next().unwrap_or(&member.name).to_string();
+    let file_type = if member.is_dir { "Directory" } else { "File" }.to_string();
+    let permissions = format!("{:o}", member.mode & 0o777);
+    let color = resolve_color_spec(&display_name, member.is_dir, false, false, member.mode & 0o111 != 0, ls_colors);
+
+    FileInfo {
+        name: display_name,
+        file_type,
+        owner: member.owner.clone(),
+        group: member.group.clone(),
+        permissions,
+        size: member.size,
+        mtime: member.mtime,
+        color,
+    }
+}
+
+/// Lists the members of an archive as synthesized `FileInfo` values.
+///
+/// # Arguments
+///
+/// * `path` - The archive to inspect.
+/// * `kind` - The detected archive format.
+/// * `ls_colors` - The parsed `LS_COLORS` lookup table used to pick a color.
+/// * `names` - Shared uid/gid name cache.
+///
+/// # Returns
+///
+/// * `io::Result<Vec<(usize, FileInfo)>>` - The member listing with depths.
+fn list_archive(
+    path: &Path,
+    kind: ArchiveKind,
+    ls_colors: &HashMap<String, ColorSpec>,
+    names: &NameCache,
+) -> io::Result<Vec<(usize, FileInfo)>> {
+    let file = fs::File::open(path)?;
+    let mut infos = Vec::new();
+
+    match kind {
+        ArchiveKind::Tar | ArchiveKind::TarGz => {
+            let reader: Box<dyn io::Read> = match kind {
+                ArchiveKind::TarGz => Box::new(flate2::read::GzDecoder::new(file)),
+                _ => Box::new(file),
+            };
+            let mut archive = tar::Archive::new(reader);
+            for entry in archive.entries()? {
+                let entry = entry?;
+                let header = entry.header();
+                let name = entry.path()?.to_string_lossy().into_owned();
+                if name.is_empty() {
+                    continue;
+                }
+                let member = ArchiveMember {
+                    is_dir: header.entry_type().is_dir(),
+                    mode: header.mode().unwrap_or(0),
+                    owner: names.owner(header.uid().unwrap_or(0) as u32),
+                    group: names.group(header.gid().unwrap_or(0) as u32),
+                    size: header.size().unwrap_or(0),
+                    mtime: header.mtime().unwrap_or(0) as i64,
+                    name,
+                };
+                infos.push((archive_depth(&member.name), archive_entry_info(&member, ls_colors)));
+            }
+        }
+        ArchiveKind::Zip => {
+            let mut archive = zip::ZipArchive::new(file)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            for i in 0..archive.len() {
+                let member = archive
+                    .by_index(i)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                // Zip only stores a unix mode in the optional extra field and
+                // carries no uid/gid, so owner/group are reported as unknown.
+                let member = ArchiveMember {
+                    name: member.name().to_string(),
+                    is_dir: member.is_dir(),
+                    mode: member.unix_mode().unwrap_or(0),
+                    owner: "Unknown".to_string(),
+                    group: "Unknown".to_string(),
+                    size: member.size(),
+                    mtime: 0,
+                };
+                infos.push((archive_depth(&member.name), archive_entry_info(&member, ls_colors)));
+            }
+        }
+    }
+
+    Ok(infos)
+}
+
 fn main() {
     let mut cmd = Command::new("file_lister")
         .version("1.0")
@@ -150,6 +736,32 @@ fn main() {
             .long("hidden")
             .action(clap::ArgAction::SetTrue)
             .help("Show hidden files"))
+        .arg(Arg::new("level")
+            .short('L')
+            .long("level")
+            .value_name("N")
+            .value_parser(clap::value_parser!(usize))
+            .help("Limit recursion to N levels deep"))
+        .arg(Arg::new("gitignore")
+            .short('i')
+            .long("gitignore")
+            .action(clap::ArgAction::SetTrue)
+            .help("Skip paths ignored by .gitignore/.ignore and global git excludes"))
+        .arg(Arg::new("sort")
+            .long("sort")
+            .value_name("KEY")
+            .value_parser(["name", "size", "time"])
+            .default_value("name")
+            .help("Sort entries by name, size, or time"))
+        .arg(Arg::new("reverse")
+            .long("reverse")
+            .action(clap::ArgAction::SetTrue)
+            .help("Reverse the sort order"))
+        .arg(Arg::new("tree")
+            .short('t')
+            .long("tree")
+            .action(clap::ArgAction::SetTrue)
+            .help("Render hierarchy with tree-style connectors"))
         .arg(Arg::new("paths")
             .value_name("PATHS")
             .help("Paths to list")
@@ -164,10 +776,22 @@ fn main() {
     }
 
     let paths: Vec<PathBuf> = matches.get_many::<String>("paths").unwrap().map(PathBuf::from).collect();
-    let recursive = matches.get_flag("recursive");
-    let show_hidden = matches.get_flag("hidden");
+    let sort = match matches.get_one::<String>("sort").map(String::as_str) {
+        Some("size") => SortKey::Size,
+        Some("time") => SortKey::Time,
+        _ => SortKey::Name,
+    };
+    let opts = ListOptions {
+        recursive: matches.get_flag("recursive"),
+        show_hidden: matches.get_flag("hidden"),
+        level: matches.get_one::<usize>("level").copied(),
+        use_ignore: matches.get_flag("gitignore"),
+        sort,
+        reverse: matches.get_flag("reverse"),
+        tree: matches.get_flag("tree"),
+    };
 
-    if let Err(e) = list_files_and_dirs(&paths, recursive, show_hidden) {
+    if let Err(e) = list_files_and_dirs(&paths, &opts) {
         eprintln!("Error listing files and directories: {}", e);
     }
 }